@@ -1,10 +1,11 @@
+//! Module for handling color scales
+//!
+//! This module provides the `ColorScale` and `Hsla` types, which are used to represent
+//! color scales and individual colors in the HSLA color space, respectively.
 
-/// Module for handling color scales
-///
-/// This module provides the `ColorScale` and `Hsla` types, which are used to represent
-/// color scales and individual colors in the HSLA color space, respectively.
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[repr(C)]
 pub struct Hsla {
     pub h: f32,
@@ -56,6 +57,108 @@ impl ColorScaleSet {
             dark_alpha,
         }
     }
+
+    /// Derives a full 12-step Radix-style `ColorScaleSet` from a single
+    /// accent color, so callers don't have to hand-author all 12 steps for
+    /// light, dark, and both alpha variants.
+    ///
+    /// Steps 1-2 are near-background app/subtle surfaces, 3-5 are component
+    /// backgrounds of increasing emphasis, 6-8 are borders of increasing
+    /// contrast, 9-10 are the solid accent and its hover, and 11-12 are
+    /// low- and high-contrast accessible text. The `light_alpha`/`dark_alpha`
+    /// scales are derived from the solid scales by expressing each step's
+    /// hue/saturation as the seed color over the scale's own background
+    /// (white for light, black for dark) with a computed alpha, so it
+    /// visually matches the solid step.
+    pub fn from_seed(name: String, seed: Hsla, hints: ColorScaleHints) -> Self {
+        let light = Self::solid_ramp(seed, &hints, Appearance::Light);
+        let dark = Self::solid_ramp(seed, &hints, Appearance::Dark);
+        let light_alpha = Self::alpha_ramp(seed, &light, hsla(0.0, 0.0, 1.0, 1.0));
+        let dark_alpha = Self::alpha_ramp(seed, &dark, hsla(0.0, 0.0, 0.0, 1.0));
+
+        ColorScaleSet {
+            name,
+            light,
+            dark,
+            light_alpha,
+            dark_alpha,
+        }
+    }
+
+    /// Builds the 12 solid steps for one appearance by interpolating
+    /// lightness along the hints' control points and scaling saturation up
+    /// toward the seed color around the solid-accent steps (9-10).
+    fn solid_ramp(seed: Hsla, hints: &ColorScaleHints, appearance: Appearance) -> ColorScale {
+        let lightness_steps = match appearance {
+            Appearance::Light => hints.light_lightness_steps,
+            Appearance::Dark => hints.dark_lightness_steps,
+        };
+
+        let mut scale = [Hsla::default(); 12];
+        for (index, lightness) in lightness_steps.into_iter().enumerate() {
+            let step = index + 1;
+            let saturation = Self::saturation_for_step(seed.s, step, hints);
+            scale[index] = hsla(seed.h, saturation, lightness, 1.0);
+        }
+        scale
+    }
+
+    /// Saturation ramps up toward the seed's own saturation as steps
+    /// approach the solid accent (9-10), and falls back for the
+    /// near-background and high-contrast text steps at either end.
+    fn saturation_for_step(seed_saturation: f32, step: usize, hints: &ColorScaleHints) -> f32 {
+        let distance_from_accent = (step as f32 - 9.5).abs();
+        let falloff = (distance_from_accent / 8.5).clamp(0.0, 1.0);
+        seed_saturation * (hints.min_saturation_factor + (1.0 - hints.min_saturation_factor) * (1.0 - falloff))
+    }
+
+    /// Derives an alpha scale from a solid scale by re-expressing each
+    /// step's hue/saturation as the seed color composited over `background`
+    /// with whatever alpha reproduces the solid step's lightness.
+    fn alpha_ramp(seed: Hsla, solid: &ColorScale, background: Hsla) -> ColorScale {
+        let mut scale = [Hsla::default(); 12];
+        for (index, step) in solid.iter().enumerate() {
+            let alpha = if (background.l - step.l).abs() < f32::EPSILON {
+                0.0
+            } else {
+                ((background.l - step.l) / (background.l - seed.l)).clamp(0.0, 1.0)
+            };
+            scale[index] = hsla(seed.h, seed.s, seed.l, alpha);
+        }
+        scale
+    }
+}
+
+/// Tunable lightness/saturation control points for [`ColorScaleSet::from_seed`].
+///
+/// Defaults follow a Radix-style ramp: light goes from ~0.99 down to ~0.25,
+/// dark inverts that (dark background up to light text), and saturation is
+/// dampened away from the solid-accent steps (9-10).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorScaleHints {
+    pub light_lightness_steps: [f32; 12],
+    pub dark_lightness_steps: [f32; 12],
+    pub min_saturation_factor: f32,
+}
+
+impl Default for ColorScaleHints {
+    fn default() -> Self {
+        Self {
+            light_lightness_steps: [
+                0.99, 0.98, 0.95, 0.91, 0.86, 0.78, 0.69, 0.58, 0.48, 0.44, 0.38, 0.25,
+            ],
+            dark_lightness_steps: [
+                0.07, 0.10, 0.14, 0.18, 0.22, 0.28, 0.35, 0.44, 0.55, 0.60, 0.72, 0.90,
+            ],
+            min_saturation_factor: 0.35,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Appearance {
+    Light,
+    Dark,
 }
 
 #[cfg(test)]
@@ -89,4 +192,20 @@ mod tests {
         assert_eq!(set.light_alpha[0], hsla(0.0, 0.0, 1.0, 0.5));
         assert_eq!(set.dark_alpha[0], hsla(0.0, 0.0, 0.0, 0.5));
     }
+
+    #[test]
+    fn test_color_scale_set_from_seed() {
+        let seed = hsla(0.6, 0.8, 0.5, 1.0);
+        let set = ColorScaleSet::from_seed("accent".to_string(), seed, ColorScaleHints::default());
+
+        assert_eq!(set.name, "accent");
+        // Light scale goes from a near-background step down to darker, more
+        // contrasty text; dark scale does the opposite.
+        assert!(set.light[0].l > set.light[11].l);
+        assert!(set.dark[0].l < set.dark[11].l);
+        // Every step keeps the seed's hue.
+        for step in set.light.iter().chain(set.dark.iter()) {
+            assert_eq!(step.h, seed.h);
+        }
+    }
 }
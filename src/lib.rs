@@ -0,0 +1,5 @@
+pub mod color_scale;
+pub mod theme;
+pub mod ui_color;
+
+pub use color_scale::{hsla, ColorScale, Hsla};
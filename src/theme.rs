@@ -1,5 +1,5 @@
 use std::{collections::{HashMap, BTreeMap}, sync::Arc, str::FromStr, path::{Path, PathBuf}};
-use crate::color::{Hsla, hsla};
+use crate::color_scale::{Hsla, hsla};
 
 use paste::paste;
 
@@ -112,16 +112,126 @@ impl<'a> serde::de::Deserialize<'a> for StandardHsla {
 #[serde(untagged)]
 enum ZedHsla {
     StandardHsla(StandardHsla),
-    Hsla(Hsla)
+    Hsla(Hsla),
+    /// Points at another UI color by name instead of holding a literal
+    /// value, e.g. `border = "text"` to keep the two colors in sync.
+    Reference(UiColorName),
+    /// Points at an entry of the theme's `Palette` by name, e.g.
+    /// `{ palette = "accent0" }`, decoupling which swatches exist from
+    /// which UI role gets which swatch.
+    PaletteRef(PaletteRef),
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+struct PaletteRef {
+    palette: String,
+}
+
+impl From<&StandardHsla> for Hsla {
+    fn from(standard: &StandardHsla) -> Self {
+        let [h, s, l, a] = standard.0;
+        hsla(h as f32 / 360.0, s as f32 / 100.0, l as f32 / 100.0, a as f32 / 100.0)
+    }
+}
+
+/// A single font modifier that can be layered onto a `Style`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Modifier {
+    Bold,
+    Dim,
+    Italic,
+    Underlined,
+    UnderlineCurl,
+    UnderlineDotted,
+    Reversed,
+    Hidden,
+    CrossedOut,
+}
+
+impl FromStr for Modifier {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "bold" => Modifier::Bold,
+            "dim" => Modifier::Dim,
+            "italic" => Modifier::Italic,
+            "underlined" => Modifier::Underlined,
+            "underline_curl" => Modifier::UnderlineCurl,
+            "underline_dotted" => Modifier::UnderlineDotted,
+            "reversed" => Modifier::Reversed,
+            "hidden" => Modifier::Hidden,
+            "crossed_out" => Modifier::CrossedOut,
+            other => return Err(anyhow::anyhow!("unknown modifier `{other}`")),
+        })
+    }
+}
+
+impl std::fmt::Display for Modifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Modifier::Bold => "bold",
+            Modifier::Dim => "dim",
+            Modifier::Italic => "italic",
+            Modifier::Underlined => "underlined",
+            Modifier::UnderlineCurl => "underline_curl",
+            Modifier::UnderlineDotted => "underline_dotted",
+            Modifier::Reversed => "reversed",
+            Modifier::Hidden => "hidden",
+            Modifier::CrossedOut => "crossed_out",
+        };
+        f.write_str(s)
+    }
+}
+
+impl Serialize for Modifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Modifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Foreground/background colors plus font modifiers for a UI element, e.g.
+/// a highlighted token that needs to be bold-italic with a specific color.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+struct Style {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fg: Option<ZedHsla>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bg: Option<ZedHsla>,
+    #[serde(default, skip_serializing_if = "std::collections::HashSet::is_empty")]
+    modifiers: std::collections::HashSet<Modifier>,
+}
+
+/// The value a `ColorOverrides` entry can hold: either a plain color
+/// (current behavior) or an inline `Style` table describing fg/bg plus
+/// font modifiers.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+enum ColorOverrideValue {
+    Color(ZedHsla),
+    Style(Style),
 }
 
 //
 macro_rules! create_ui_color_overrides_impl {
     ($($field:ident: $t:ty),*) => {
 
-        paste! { #[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Deserialize, Serialize)]
+        paste! { #[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize, Serialize)]
             #[serde(rename_all="snake_case")]
-            enum UiColorName {
+            pub enum UiColorName {
             $([<$field:camel>]),*
         }
         }
@@ -129,7 +239,7 @@ macro_rules! create_ui_color_overrides_impl {
             $($field: $t),*
         }
         #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
-        struct ColorOverrides(BTreeMap<UiColorName, ZedHsla>);
+        struct ColorOverrides(BTreeMap<UiColorName, ColorOverrideValue>);
     };
 }
 
@@ -153,6 +263,62 @@ enum Appearance {
 
 type ThemeId = usize;
 
+/// A named, ordered set of base colors (a base16-style palette: background,
+/// surfaces, selection, comments, and eight accents) that a `ThemeVariant`
+/// can reference by name instead of holding literal colors, decoupling
+/// "which swatches exist" from "which UI role gets which swatch." Changing
+/// the palette a theme names restyles every role that references it
+/// without editing a single override.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    pub name: String,
+    pub background: Hsla,
+    pub surface: Hsla,
+    pub selection: Hsla,
+    pub comment: Hsla,
+    pub accents: [Hsla; 8],
+}
+
+impl Palette {
+    /// Looks up an entry by name: `background`, `surface`, `selection`,
+    /// `comment`, or `accent0`..`accent7`.
+    pub fn get(&self, name: &str) -> Option<Hsla> {
+        match name {
+            "background" => Some(self.background),
+            "surface" => Some(self.surface),
+            "selection" => Some(self.selection),
+            "comment" => Some(self.comment),
+            other => other
+                .strip_prefix("accent")
+                .and_then(|index| index.parse::<usize>().ok())
+                .and_then(|index| self.accents.get(index).copied()),
+        }
+    }
+}
+
+/// The compiled-in base16 palette. Every `ThemeRegistry` registers it under
+/// the name `base16_default`; a theme uses it only by naming it in its
+/// `palette` field.
+pub fn base16_default() -> Palette {
+    Palette {
+        name: "base16_default".to_string(),
+        background: hsla(0.0, 0.0, 0.08, 1.0),
+        surface: hsla(0.0, 0.0, 0.14, 1.0),
+        selection: hsla(0.0, 0.0, 0.22, 1.0),
+        comment: hsla(0.0, 0.0, 0.45, 1.0),
+        accents: [
+            hsla(0.0, 0.55, 0.55, 1.0),
+            hsla(0.08, 0.6, 0.55, 1.0),
+            hsla(0.15, 0.6, 0.55, 1.0),
+            hsla(0.33, 0.45, 0.5, 1.0),
+            hsla(0.5, 0.45, 0.55, 1.0),
+            hsla(0.58, 0.5, 0.6, 1.0),
+            hsla(0.75, 0.45, 0.6, 1.0),
+            hsla(0.9, 0.5, 0.6, 1.0),
+        ],
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ThemeVariant {
     #[serde(skip)]
@@ -160,9 +326,120 @@ pub struct ThemeVariant {
     name: String,
     author: String,
     appearance: Appearance,
+    /// Name of a parent theme to inherit overrides from. Any key the child
+    /// leaves unspecified falls through to the parent's resolved value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    extends: Option<String>,
+    /// Name of the `Palette` that this theme's `ZedHsla::PaletteRef`
+    /// overrides are resolved against.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    palette: Option<String>,
     overrides: ColorOverrides,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    InProgress,
+    Done,
+}
+
+/// Resolves a single override entry to a concrete `Hsla`, following any
+/// chain of `ZedHsla::Reference`s. `path` tracks the keys currently being
+/// resolved so a cycle can be reported by the names that make it up.
+fn resolve_color_override(
+    key: &UiColorName,
+    overrides: &BTreeMap<UiColorName, ColorOverrideValue>,
+    state: &mut HashMap<UiColorName, VisitState>,
+    resolved: &mut BTreeMap<UiColorName, Hsla>,
+    path: &mut Vec<UiColorName>,
+    palette: Option<&Palette>,
+) -> Result<Hsla, anyhow::Error> {
+    if let Some(color) = resolved.get(key) {
+        return Ok(*color);
+    }
+    if state.get(key) == Some(&VisitState::InProgress) {
+        path.push(key.clone());
+        let names = path
+            .iter()
+            .map(|name| format!("{:?}", name))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        return Err(anyhow::anyhow!("color reference cycle detected: {names}"));
+    }
+
+    let value = overrides
+        .get(key)
+        .ok_or_else(|| anyhow::anyhow!("reference to undefined color `{:?}`", key))?;
+    let zed_hsla = match value {
+        ColorOverrideValue::Color(zed_hsla) => zed_hsla,
+        ColorOverrideValue::Style(_) => {
+            return Err(anyhow::anyhow!(
+                "`{:?}` is a style, not a plain color, and cannot be used as a color reference",
+                key
+            ))
+        }
+    };
+
+    state.insert(key.clone(), VisitState::InProgress);
+    path.push(key.clone());
+
+    let color = resolve_zed_hsla(zed_hsla, overrides, state, resolved, path, palette)?;
+
+    path.pop();
+    state.insert(key.clone(), VisitState::Done);
+    resolved.insert(key.clone(), color);
+    Ok(color)
+}
+
+/// Resolves a standalone `ZedHsla` value (e.g. a `Style`'s `fg`/`bg`, which
+/// isn't itself a keyed override) to a concrete `Hsla`, following the same
+/// reference/palette rules as `resolve_color_override`.
+fn resolve_zed_hsla(
+    zed_hsla: &ZedHsla,
+    overrides: &BTreeMap<UiColorName, ColorOverrideValue>,
+    state: &mut HashMap<UiColorName, VisitState>,
+    resolved: &mut BTreeMap<UiColorName, Hsla>,
+    path: &mut Vec<UiColorName>,
+    palette: Option<&Palette>,
+) -> Result<Hsla, anyhow::Error> {
+    match zed_hsla {
+        ZedHsla::StandardHsla(standard) => Ok(standard.into()),
+        ZedHsla::Hsla(hsla) => Ok(*hsla),
+        ZedHsla::Reference(target) => {
+            resolve_color_override(target, overrides, state, resolved, path, palette)
+        }
+        ZedHsla::PaletteRef(reference) => {
+            let palette = palette.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "references palette entry `{}` but the theme has no palette configured",
+                    reference.palette
+                )
+            })?;
+            palette.get(&reference.palette).ok_or_else(|| {
+                anyhow::anyhow!("palette `{}` has no entry named `{}`", palette.name, reference.palette)
+            })
+        }
+    }
+}
+
+/// A `Style` with its `fg`/`bg` flattened to concrete colors. Modifiers are
+/// exposed by name, since `Modifier` itself is private to this module.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResolvedStyle {
+    pub fg: Option<Hsla>,
+    pub bg: Option<Hsla>,
+    pub modifiers: std::collections::HashSet<String>,
+}
+
+/// Every override on a theme, flattened to concrete colors: plain colors in
+/// `colors`, and `Style` entries (with their own `fg`/`bg` references
+/// resolved) in `styles`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResolvedColors {
+    pub colors: BTreeMap<UiColorName, Hsla>,
+    pub styles: BTreeMap<UiColorName, ResolvedStyle>,
+}
+
 pub fn serialize_theme(t: ThemeVariant) -> Result<String, anyhow::Error> {
     Ok(toml::to_string_pretty(&t)?)
 }
@@ -176,10 +453,283 @@ pub fn write_theme(t: ThemeVariant) -> Result<(), anyhow::Error> {
     write_theme_to(t, &PathBuf::from("theme"))
 }
 
+impl ThemeVariant {
+    /// Flattens every override to concrete colors, following any
+    /// `ZedHsla::Reference` chains, including those nested in a `Style`'s
+    /// `fg`/`bg`. Errors on a reference to an undefined key, a cycle among
+    /// the overrides, or a `ZedHsla::PaletteRef` (which needs a `Palette` —
+    /// use `ThemeRegistry::resolve_colors` for those).
+    pub fn resolve_colors(&self) -> Result<ResolvedColors, anyhow::Error> {
+        self.resolve_colors_with_palette(None)
+    }
+
+    fn resolve_colors_with_palette(
+        &self,
+        palette: Option<&Palette>,
+    ) -> Result<ResolvedColors, anyhow::Error> {
+        let mut state = HashMap::new();
+        let mut resolved = BTreeMap::new();
+        let mut styles = BTreeMap::new();
+
+        for (key, value) in self.overrides.0.iter() {
+            match value {
+                ColorOverrideValue::Color(_) => {
+                    let mut path = Vec::new();
+                    resolve_color_override(key, &self.overrides.0, &mut state, &mut resolved, &mut path, palette)?;
+                }
+                ColorOverrideValue::Style(style) => {
+                    let fg = style
+                        .fg
+                        .as_ref()
+                        .map(|zed_hsla| {
+                            resolve_zed_hsla(zed_hsla, &self.overrides.0, &mut state, &mut resolved, &mut Vec::new(), palette)
+                        })
+                        .transpose()?;
+                    let bg = style
+                        .bg
+                        .as_ref()
+                        .map(|zed_hsla| {
+                            resolve_zed_hsla(zed_hsla, &self.overrides.0, &mut state, &mut resolved, &mut Vec::new(), palette)
+                        })
+                        .transpose()?;
+                    styles.insert(
+                        key.clone(),
+                        ResolvedStyle {
+                            fg,
+                            bg,
+                            modifiers: style.modifiers.iter().map(|modifier| modifier.to_string()).collect(),
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok(ResolvedColors { colors: resolved, styles })
+    }
+}
+
+/// Recursively merges two decoded TOML tables, with `child` entries winning
+/// over `parent` entries and unspecified keys falling through to `parent`.
+/// Nested tables are combined key-by-key; any other value in `child` simply
+/// replaces the corresponding value in `parent`.
+fn merge_toml_values(parent: &toml::Value, child: &toml::Value) -> toml::Value {
+    match (parent, child) {
+        (toml::Value::Table(parent), toml::Value::Table(child)) => {
+            let mut merged = parent.clone();
+            for (key, child_value) in child {
+                let value = match merged.get(key) {
+                    Some(parent_value) => merge_toml_values(parent_value, child_value),
+                    None => child_value.clone(),
+                };
+                merged.insert(key.clone(), value);
+            }
+            toml::Value::Table(merged)
+        }
+        (_, child) => child.clone(),
+    }
+}
+
 #[derive(Debug)]
 pub struct ThemeRegistry {
     themes: HashMap<ThemeId, Arc<ThemeVariant>>,
-    current: Option<Arc<ThemeVariant>>
+    current: Option<Arc<ThemeVariant>>,
+    next_id: ThemeId,
+    palettes: HashMap<String, Palette>,
+}
+
+impl Default for ThemeRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThemeRegistry {
+    pub fn new() -> Self {
+        let default_palette = base16_default();
+        let mut palettes = HashMap::new();
+        palettes.insert(default_palette.name.clone(), default_palette);
+
+        Self {
+            themes: HashMap::new(),
+            current: None,
+            next_id: 0,
+            palettes,
+        }
+    }
+
+    /// Registers an already-constructed theme, assigning it a fresh `ThemeId`.
+    pub fn insert(&mut self, mut theme: ThemeVariant) -> ThemeId {
+        let id = self.next_id;
+        self.next_id += 1;
+        theme.id = id;
+        self.themes.insert(id, Arc::new(theme));
+        id
+    }
+
+    pub fn get(&self, id: ThemeId) -> Option<&Arc<ThemeVariant>> {
+        self.themes.get(&id)
+    }
+
+    /// Registers a user-provided palette, making it available to any theme
+    /// whose `palette` field names it.
+    pub fn register_palette(&mut self, palette: Palette) {
+        self.palettes.insert(palette.name.clone(), palette);
+    }
+
+    pub fn find_palette(&self, name: &str) -> Option<&Palette> {
+        self.palettes.get(name)
+    }
+
+    /// Flattens every override on `theme` to concrete colors, expanding
+    /// `ZedHsla::PaletteRef` entries (including those nested in a `Style`'s
+    /// `fg`/`bg`) against the theme's registered `Palette` along the way.
+    pub fn resolve_colors(&self, theme: &ThemeVariant) -> Result<ResolvedColors, anyhow::Error> {
+        let palette = match &theme.palette {
+            Some(name) => Some(self.find_palette(name).ok_or_else(|| {
+                anyhow::anyhow!("theme `{}` references unknown palette `{}`", theme.name, name)
+            })?),
+            None => None,
+        };
+        theme.resolve_colors_with_palette(palette)
+    }
+
+    pub fn find_by_name(&self, name: &str) -> Option<&Arc<ThemeVariant>> {
+        self.themes.values().find(|theme| theme.name == name)
+    }
+
+    /// Parses a theme from raw TOML, resolving its `extends` chain (if any)
+    /// against themes already registered, and stores the result.
+    ///
+    /// The parent must already be registered: because every theme is fully
+    /// resolved before being stored, looking up one direct parent is enough
+    /// to inherit from arbitrarily deep chains.
+    pub fn register_toml(&mut self, raw: &str) -> Result<ThemeId, anyhow::Error> {
+        let mut value: toml::Value = toml::from_str(raw)?;
+        let table = value
+            .as_table_mut()
+            .ok_or_else(|| anyhow::anyhow!("theme must be a TOML table"))?;
+
+        if let Some(parent_name) = table.get("extends").and_then(|v| v.as_str()) {
+            let child_name = table.get("name").and_then(|v| v.as_str()).unwrap_or("");
+            if parent_name == child_name {
+                return Err(anyhow::anyhow!(
+                    "theme `{child_name}` cannot extend itself"
+                ));
+            }
+            let parent = self.find_by_name(parent_name).ok_or_else(|| {
+                anyhow::anyhow!("theme `{child_name}` extends unknown parent `{parent_name}`")
+            })?;
+            let parent_overrides = toml::Value::try_from(&parent.overrides)?;
+            if let Some(child_overrides) = table.get("overrides") {
+                let merged = merge_toml_values(&parent_overrides, child_overrides);
+                table.insert("overrides".to_string(), merged);
+            } else {
+                table.insert("overrides".to_string(), parent_overrides);
+            }
+        }
+
+        let merged_toml = toml::to_string(&value)?;
+        let theme: ThemeVariant = toml::from_str(&merged_toml)?;
+        Ok(self.insert(theme))
+    }
+}
+
+// ====================
+// Loader
+// ====================
+
+/// Compiled-in default themes, embedded at build time so the app always has
+/// something to fall back on even with no theme directories configured.
+const EMBEDDED_DEFAULT_THEMES: &[(&str, &str)] = &[
+    ("default-dark", include_str!("themes/default_dark.toml")),
+    ("default-light", include_str!("themes/default_light.toml")),
+];
+
+/// Reads themes from disk, preferring earlier directories over later ones,
+/// and falling back to the compiled-in defaults when nothing on disk
+/// matches. Resolved themes are wired into a `ThemeRegistry`.
+#[derive(Debug, Clone)]
+pub struct Loader {
+    /// Directories to search, highest priority first.
+    directories: Vec<PathBuf>,
+}
+
+impl Loader {
+    pub fn new(directories: Vec<PathBuf>) -> Self {
+        Self { directories }
+    }
+
+    fn embedded_theme(name: &str) -> Option<&'static str> {
+        EMBEDDED_DEFAULT_THEMES
+            .iter()
+            .find(|(embedded_name, _)| *embedded_name == name)
+            .map(|(_, contents)| *contents)
+    }
+
+    /// Loads `name`, checking each directory in priority order first so a
+    /// user-supplied theme can shadow a compiled-in default of the same
+    /// name, then falling back to the embedded defaults. This matches
+    /// `names()`, which lists directory themes ahead of embedded ones.
+    /// Registers the resolved theme with `registry` and returns it.
+    pub fn load(&self, registry: &mut ThemeRegistry, name: &str) -> Result<ThemeVariant, anyhow::Error> {
+        for directory in &self.directories {
+            let path = directory.join(format!("{name}.toml"));
+            if path.is_file() {
+                let raw = std::fs::read_to_string(&path)?;
+                return self.register(registry, &raw);
+            }
+        }
+
+        if let Some(raw) = Self::embedded_theme(name) {
+            return self.register(registry, raw);
+        }
+
+        Err(anyhow::anyhow!(
+            "theme `{name}` not found in embedded defaults or search directories"
+        ))
+    }
+
+    fn register(&self, registry: &mut ThemeRegistry, raw: &str) -> Result<ThemeVariant, anyhow::Error> {
+        let id = registry.register_toml(raw)?;
+        registry
+            .get(id)
+            .map(|theme| (**theme).clone())
+            .ok_or_else(|| anyhow::anyhow!("theme vanished from registry immediately after registration"))
+    }
+
+    /// Enumerates every theme name available across the embedded defaults
+    /// and all search directories, de-duplicated, with earlier directories
+    /// shadowing later ones.
+    pub fn names(&self) -> Vec<String> {
+        let mut seen = std::collections::HashSet::new();
+        let mut names = Vec::new();
+
+        for directory in &self.directories {
+            let Ok(entries) = std::fs::read_dir(directory) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("toml") {
+                    continue;
+                }
+                let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                    continue;
+                };
+                if seen.insert(stem.to_string()) {
+                    names.push(stem.to_string());
+                }
+            }
+        }
+
+        for (name, _) in EMBEDDED_DEFAULT_THEMES {
+            if seen.insert(name.to_string()) {
+                names.push(name.to_string());
+            }
+        }
+
+        names
+    }
 }
 
 // ====================
@@ -187,4 +737,255 @@ pub struct ThemeRegistry {
 // ====================
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extends_inherits_parent_overrides_and_lets_child_override_them() {
+        let mut registry = ThemeRegistry::new();
+        registry
+            .register_toml(
+                r#"
+                name = "parent"
+                author = "Test"
+                appearance = "dark"
+
+                [overrides]
+                background = [0, 0, 10, 100]
+                border = [0, 0, 20, 100]
+                "#,
+            )
+            .unwrap();
+
+        let child_id = registry
+            .register_toml(
+                r#"
+                name = "child"
+                author = "Test"
+                appearance = "dark"
+                extends = "parent"
+
+                [overrides]
+                border = [0, 0, 50, 100]
+                "#,
+            )
+            .unwrap();
+
+        let child = registry.get(child_id).unwrap();
+        let resolved = child.resolve_colors().unwrap();
+        assert_eq!(
+            resolved.colors.get(&UiColorName::Background),
+            Some(&hsla(0.0, 0.0, 0.10, 1.0))
+        );
+        assert_eq!(
+            resolved.colors.get(&UiColorName::Border),
+            Some(&hsla(0.0, 0.0, 0.50, 1.0))
+        );
+    }
+
+    #[test]
+    fn extends_unknown_parent_is_an_error() {
+        let mut registry = ThemeRegistry::new();
+        let result = registry.register_toml(
+            r#"
+            name = "orphan"
+            author = "Test"
+            appearance = "dark"
+            extends = "nonexistent"
+
+            [overrides]
+            background = [0, 0, 10, 100]
+            "#,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn references_resolve_to_the_target_colors_value() {
+        let mut registry = ThemeRegistry::new();
+        let id = registry
+            .register_toml(
+                r#"
+                name = "aliased"
+                author = "Test"
+                appearance = "dark"
+
+                [overrides]
+                text = [0, 0, 90, 100]
+                border = "text"
+                "#,
+            )
+            .unwrap();
+
+        let theme = registry.get(id).unwrap();
+        let resolved = theme.resolve_colors().unwrap();
+        assert_eq!(
+            resolved.colors.get(&UiColorName::Border),
+            resolved.colors.get(&UiColorName::Text)
+        );
+    }
+
+    #[test]
+    fn reference_cycles_are_rejected() {
+        let mut registry = ThemeRegistry::new();
+        let id = registry
+            .register_toml(
+                r#"
+                name = "cyclic"
+                author = "Test"
+                appearance = "dark"
+
+                [overrides]
+                border = "text"
+                text = "border"
+                "#,
+            )
+            .unwrap();
+
+        let theme = registry.get(id).unwrap();
+        let error = theme.resolve_colors().unwrap_err();
+        assert!(error.to_string().contains("cycle"));
+    }
+
+    #[test]
+    fn loader_falls_back_to_embedded_defaults_when_no_directory_has_the_theme() {
+        let loader = Loader::new(Vec::new());
+        let mut registry = ThemeRegistry::new();
+        let theme = loader.load(&mut registry, "default-dark").unwrap();
+        assert_eq!(theme.name, "default-dark");
+        assert!(loader.names().contains(&"default-dark".to_string()));
+    }
+
+    #[test]
+    fn loader_prefers_a_directory_theme_over_an_embedded_default_of_the_same_name() {
+        let dir = std::env::temp_dir().join(format!(
+            "theme_test_loader_{}_{}",
+            std::process::id(),
+            line!()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("default-dark.toml"),
+            r#"
+            name = "default-dark"
+            author = "Custom"
+            appearance = "dark"
+
+            [overrides]
+            background = [0, 0, 0, 100]
+            "#,
+        )
+        .unwrap();
+
+        let loader = Loader::new(vec![dir.clone()]);
+        let mut registry = ThemeRegistry::new();
+        let theme = loader.load(&mut registry, "default-dark").unwrap();
+        assert_eq!(theme.author, "Custom");
+
+        let names = loader.names();
+        assert_eq!(names.iter().filter(|name| *name == "default-dark").count(), 1);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn loader_errors_on_unknown_theme_name() {
+        let loader = Loader::new(Vec::new());
+        let mut registry = ThemeRegistry::new();
+        assert!(loader.load(&mut registry, "does-not-exist").is_err());
+    }
+
+    #[test]
+    fn style_overrides_round_trip_through_toml_alongside_plain_colors() {
+        let raw = r#"
+            name = "styled"
+            author = "Test"
+            appearance = "dark"
+
+            [overrides]
+            background = [0, 0, 10, 100]
+            text = [0, 0, 90, 100]
+
+            [overrides.border]
+            fg = [0, 100, 50, 100]
+            modifiers = ["bold", "italic"]
+            "#;
+
+        let theme: ThemeVariant = toml::from_str(raw).unwrap();
+        let serialized = serialize_theme(theme.clone()).unwrap();
+        let round_tripped: ThemeVariant = toml::from_str(&serialized).unwrap();
+        assert_eq!(theme.overrides, round_tripped.overrides);
+    }
+
+    #[test]
+    fn style_fg_bg_references_are_resolved_instead_of_dropped() {
+        let mut registry = ThemeRegistry::new();
+        let id = registry
+            .register_toml(
+                r#"
+                name = "styled-alias"
+                author = "Test"
+                appearance = "dark"
+
+                [overrides]
+                text = [0, 0, 90, 100]
+
+                [overrides.border]
+                fg = "text"
+                modifiers = ["bold"]
+                "#,
+            )
+            .unwrap();
+
+        let theme = registry.get(id).unwrap();
+        let resolved = theme.resolve_colors().unwrap();
+        let border_style = resolved.styles.get(&UiColorName::Border).unwrap();
+        assert_eq!(border_style.fg, resolved.colors.get(&UiColorName::Text).copied());
+        assert!(border_style.modifiers.contains("bold"));
+    }
+
+    #[test]
+    fn palette_ref_resolves_against_the_themes_registered_palette() {
+        let mut registry = ThemeRegistry::new();
+        let id = registry
+            .register_toml(
+                r#"
+                name = "palette-themed"
+                author = "Test"
+                appearance = "dark"
+                palette = "base16_default"
+
+                [overrides.background]
+                palette = "background"
+                "#,
+            )
+            .unwrap();
+
+        let theme = registry.get(id).unwrap();
+        let resolved = registry.resolve_colors(theme).unwrap();
+        assert_eq!(
+            resolved.colors.get(&UiColorName::Background),
+            Some(&base16_default().background)
+        );
+    }
+
+    #[test]
+    fn palette_ref_without_a_registered_palette_is_an_error() {
+        let mut registry = ThemeRegistry::new();
+        let id = registry
+            .register_toml(
+                r#"
+                name = "no-palette"
+                author = "Test"
+                appearance = "dark"
+
+                [overrides.background]
+                palette = "background"
+                "#,
+            )
+            .unwrap();
+
+        let theme = registry.get(id).unwrap();
+        assert!(theme.resolve_colors().is_err());
+    }
+}